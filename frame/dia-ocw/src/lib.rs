@@ -2,23 +2,31 @@
 
 use frame_system::{
 	self as system,
-	ensure_signed,
+	ensure_signed, ensure_none, ensure_root,
 	offchain::{
-		AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer,
+		AppCrypto, CreateSignedTransaction, SendSignedTransaction, SendUnsignedTransaction,
+		Signer, SignedPayload, SigningTypes,
 	}
 };
 use frame_support::{
-	debug,
-	dispatch::DispatchResult, decl_module, decl_storage, decl_event,
+	debug, ensure,
+	dispatch::DispatchResult, decl_module, decl_storage, decl_event, decl_error,
 };
+use codec::{Encode, Decode};
 use sp_core::crypto::KeyTypeId;
 use sp_runtime::{
 	offchain::{http, Duration},
+	transaction_validity::{
+		InvalidTransaction, TransactionSource, TransactionValidity, ValidTransaction,
+	},
+	RuntimeDebug,
 };
 use sp_std::vec::Vec;
-use sp_std::cell::RefCell;
-use frame_support::traits::IsType;
-use sp_runtime::traits::BadOrigin;
+use sp_std::marker::PhantomData;
+use frame_support::traits::{Get, IsType};
+use sp_runtime::traits::IdentifyAccount;
+use sp_runtime::transaction_validity::TransactionPriority;
+use sp_runtime::offchain::storage::StorageValueRef;
 
 #[cfg(test)]
 mod tests;
@@ -48,45 +56,310 @@ pub mod crypto {
 	}
 }
 
+/// A parsed DIA quotation. `price` and `volume_yesterday` are fixed-point, scaled by
+/// 10^`PRICE_DECIMALS`, so the pallet never has to carry a float in `no_std`.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct Quotation {
+	pub symbol: Vec<u8>,
+	pub price: u128,
+	pub volume_yesterday: u128,
+	pub timestamp: u64,
+}
+
+/// Payload carried by an unsigned transaction that still proves, via `public`, which
+/// oracle key produced it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PricePayload<Public, BlockNumber> {
+	block_number: BlockNumber,
+	quotation: Quotation,
+	public: Public,
+}
+
+impl<T: SigningTypes> SignedPayload<T> for PricePayload<T::Public, T::BlockNumber> {
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
+
+/// A source of raw DIA quotation bytes for a symbol, queried against a specific mirror
+/// endpoint. Implementations are stackable: `FallbackSource` and `CachedSource` both wrap
+/// an inner `DataSource`, so a runtime can compose retry, caching and alternate providers
+/// purely in its `Trait` impl without forking the pallet.
+///
+/// Takes `endpoint` as well as `symbol`, not `symbol` alone: `fetch_quotations` queries
+/// every configured mirror per symbol, so a source needs to know which mirror it's being
+/// asked for in order to build the right request URL, cache key, etc.
+pub trait DataSource {
+	fn fetch(endpoint: &'static str, symbol: &[u8]) -> Result<Vec<u8>, http::Error>;
+}
+
+/// Fetches quotation bytes directly over HTTP, built from `endpoint` and `symbol`.
+pub struct HttpSource;
+
+impl DataSource for HttpSource {
+	fn fetch(endpoint: &'static str, symbol: &[u8]) -> Result<Vec<u8>, http::Error> {
+		let mut url = endpoint.as_bytes().to_vec();
+		url.extend_from_slice(symbol);
+		let url_str = sp_runtime::sp_std::str::from_utf8(&url).map_err(|_| http::Error::Unknown)?;
+
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(2_000));
+		let request = http::Request::get(url_str);
+		let pending = request
+			.deadline(deadline)
+			.send()
+			.map_err(|_| http::Error::IoError)?;
+
+		let response = pending.try_wait(deadline)
+			.map_err(|_| http::Error::DeadlineReached)??;
+		if response.code != 200 {
+			debug::warn!("Unexpected status code: {}", response.code);
+			return Err(http::Error::Unknown);
+		}
+
+		Ok(response.body().collect::<Vec<u8>>())
+	}
+}
+
+/// Tries data source `A`, falling back to `B` if `A` fails. Stack further fallbacks by
+/// nesting, e.g. `FallbackSource<HttpSource, FallbackSource<HttpSource, HttpSource>>`.
+pub struct FallbackSource<A, B>(PhantomData<(A, B)>);
+
+impl<A: DataSource, B: DataSource> DataSource for FallbackSource<A, B> {
+	fn fetch(endpoint: &'static str, symbol: &[u8]) -> Result<Vec<u8>, http::Error> {
+		match A::fetch(endpoint, symbol) {
+			Ok(body) => Ok(body),
+			Err(e) => {
+				debug::warn!("Primary data source failed ({:?}), falling back", e);
+				B::fetch(endpoint, symbol)
+			}
+		}
+	}
+}
+
+/// Wraps `S`, serving a cached response from offchain local storage while it is younger
+/// than `TTL_MS`, so a slow-moving feed doesn't hammer the upstream API every block.
+pub struct CachedSource<S>(PhantomData<S>);
+
+impl<S: DataSource> CachedSource<S> {
+	const TTL_MS: u64 = 30_000;
+}
+
+impl<S: DataSource> DataSource for CachedSource<S> {
+	fn fetch(endpoint: &'static str, symbol: &[u8]) -> Result<Vec<u8>, http::Error> {
+		let mut key = b"dia-ocw::cache::".to_vec();
+		key.extend_from_slice(endpoint.as_bytes());
+		key.extend_from_slice(symbol);
+		let mut storage = StorageValueRef::persistent(&key);
+
+		if let Some(Some((cached_at, body))) = storage.get::<(u64, Vec<u8>)>() {
+			let now = sp_io::offchain::timestamp().unix_millis();
+			if now.saturating_sub(cached_at) < Self::TTL_MS {
+				return Ok(body);
+			}
+		}
+
+		let body = S::fetch(endpoint, symbol)?;
+		let now = sp_io::offchain::timestamp().unix_millis();
+		storage.set(&(now, body.clone()));
+		Ok(body)
+	}
+}
+
 pub trait Trait: CreateSignedTransaction<Call<Self>> {
 	type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
 	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 	type Call: From<Call<Self>>;
+
+	/// Whether the offchain worker feeds the chain through a signed transaction (`false`,
+	/// requires a funded `dia!` key) or an unsigned transaction carrying a
+	/// signed payload (gas-less, requires only a registered key).
+	const SUBMIT_UNSIGNED: bool;
+
+	/// Priority assigned to unsigned submissions in the transaction pool.
+	type UnsignedPriority: Get<TransactionPriority>;
+
+	/// Mirror endpoints queried for every tracked symbol. Each endpoint's response is
+	/// submitted as its own observation, but only the latest one per authority is kept
+	/// on-chain; `on_finalize` aggregates the observations of distinct authorities into
+	/// a median.
+	const ENDPOINTS: &'static [&'static str];
+
+	/// Maximum percentage an observation may deviate from the currently stored price
+	/// before it is discarded as an outlier.
+	type OutlierTolerancePercent: Get<u32>;
+
+	/// Where quotation bytes are fetched from for each endpoint in `ENDPOINTS`. Runtimes
+	/// compose retry, caching and fallback providers here, e.g.
+	/// `CachedSource<FallbackSource<HttpSource, HttpSource>>`.
+	type DataSource: DataSource;
 }
 
+const PRICE_DECIMALS: u32 = 8;
+
 decl_storage! {
 	trait Store for Module<T: Trait> as DIAOCW {
-		DiaData get(fn data): Vec<u8>;
+		/// Committed quotation, aggregated by median, for each tracked symbol.
+		DiaData get(fn data): map hasher(blake2_128_concat) Vec<u8> => Quotation;
+		/// Per-block quotation observations from distinct authorities, drained and
+		/// aggregated into `DiaData` by `on_finalize`.
+		Observations: map hasher(blake2_128_concat) Vec<u8> => Vec<(T::AccountId, Quotation)>;
+		/// Symbols the offchain worker fetches a quotation for on every block.
+		TrackedAssets get(fn tracked_assets): Vec<Vec<u8>>;
+		/// Block number at which the next round of unsigned submissions for a symbol will be
+		/// accepted, keyed per symbol so that rate-limiting one symbol doesn't stall another.
+		/// Advanced by `submit_data_unsigned` itself to one past the block the payload was
+		/// stamped with, the block `ValidateUnsigned` checked it against — advancing it from
+		/// `on_finalize`'s own block number would put it one block ahead of what a payload
+		/// stamped earlier in that same round is validated against, rejecting every other
+		/// round of submissions as stale.
+		NextUnsignedAt get(fn next_unsigned_at): map hasher(blake2_128_concat) Vec<u8> => T::BlockNumber;
+		/// Accounts of the oracle authorities allowed to feed `DiaData`, managed by root.
+		/// Signatures are always checked against the public key carried in the payload
+		/// itself (see `ValidateUnsigned`), so this only ever needs to answer a plain
+		/// membership question and can store `T::AccountId` directly.
+		Authorities get(fn authorities): Vec<T::AccountId>;
 	}
 }
 
 decl_event!(
 	pub enum Event<T> where AccountId = <T as frame_system::Trait>::AccountId {
-		NewDiaData(Vec<u8>, AccountId),
+		AuthorityAdded(AccountId),
+		AuthorityRemoved(AccountId),
+		/// An authority replaced its own allowlisted account; carries the old and new one.
+		AuthorityRotated(AccountId, AccountId),
+		/// A symbol's committed median price and the number of contributing sources.
+		PriceCommitted(Vec<u8>, u128, u32),
 	}
 );
 
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		/// The submitting account is not a registered oracle authority.
+		NotAnAuthority,
+		/// The rotation target is already on the authority allowlist.
+		DuplicateAuthorityKey,
+	}
+}
+
 decl_module! {
 	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
 		fn deposit_event() = default;
 
+		/// Submit a single quotation observation which was fetched and signed off-chain by
+		/// a funded `dia!` key belonging to a registered authority. Observations are
+		/// aggregated into a median and committed to `DiaData` by `on_finalize`.
 		#[weight = 10_000]
-		pub fn submit_data(origin, price: Vec<u8>) -> DispatchResult {
+		pub fn submit_data(origin, quotation: Quotation) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let body_str = sp_runtime::sp_std::str::from_utf8(&price).map_err(|_| {
-				debug::warn!("No UTF8 body");
-				BadOrigin
+			ensure!(Self::is_authority(&who), Error::<T>::NotAnAuthority);
+			Self::record_observation(quotation, who);
+			Ok(())
+		}
+
+		/// Submit a single quotation observation via an unsigned transaction carrying a
+		/// payload signed by a registered authority's `dia!` key. The signature is checked
+		/// in `ValidateUnsigned`; authority membership is checked there and again here.
+		///
+		/// Advances `NextUnsignedAt` for this symbol from the *stamped* block (the one the
+		/// offchain worker ran at, not the block this call happens to land in), so it lines
+		/// up with the value `ValidateUnsigned` checked the payload against at submission time.
+		#[weight = 10_000]
+		pub fn submit_data_unsigned(origin, payload: PricePayload<T::Public, T::BlockNumber>, _signature: T::Signature) -> DispatchResult {
+			ensure_none(origin)?;
+			let PricePayload { block_number, quotation, public } = payload;
+			let who = public.into_account();
+			ensure!(Self::is_authority(&who), Error::<T>::NotAnAuthority);
+			let symbol = quotation.symbol.clone();
+			Self::record_observation(quotation, who);
+			NextUnsignedAt::<T>::insert(symbol, block_number + 1u32.into());
+			Ok(())
+		}
+
+		/// Start tracking a DIA quotation symbol, managed by root or an existing authority.
+		#[weight = 10_000]
+		pub fn add_asset(origin, symbol: Vec<u8>) -> DispatchResult {
+			Self::ensure_root_or_authority(origin)?;
+			TrackedAssets::<T>::mutate(|assets| {
+				if !assets.contains(&symbol) {
+					assets.push(symbol);
+				}
+			});
+			Ok(())
+		}
+
+		/// Stop tracking a DIA quotation symbol, managed by root or an existing authority.
+		#[weight = 10_000]
+		pub fn remove_asset(origin, symbol: Vec<u8>) -> DispatchResult {
+			Self::ensure_root_or_authority(origin)?;
+			TrackedAssets::<T>::mutate(|assets| assets.retain(|s| s != &symbol));
+			Ok(())
+		}
+
+		/// Add an account to the oracle authority allowlist.
+		#[weight = 10_000]
+		pub fn add_authority(origin, authority: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+			Authorities::<T>::mutate(|authorities| {
+				if !authorities.contains(&authority) {
+					authorities.push(authority.clone());
+				}
+			});
+			Self::deposit_event(RawEvent::AuthorityAdded(authority));
+			Ok(())
+		}
+
+		/// Remove an account from the oracle authority allowlist.
+		#[weight = 10_000]
+		pub fn remove_authority(origin, authority: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+			Authorities::<T>::mutate(|authorities| authorities.retain(|a| a != &authority));
+			Self::deposit_event(RawEvent::AuthorityRemoved(authority));
+			Ok(())
+		}
+
+		/// Let an authority atomically replace its own allowlisted account, e.g. after
+		/// rotating to a new `dia!`-derived account, without a root round-trip.
+		#[weight = 10_000]
+		pub fn rotate_authority_key(origin, new_key: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Authorities::<T>::get().iter().all(|a| a != &new_key),
+				Error::<T>::DuplicateAuthorityKey
+			);
+			let old_key = Authorities::<T>::try_mutate(|authorities| -> Result<T::AccountId, Error<T>> {
+				let slot = authorities.iter_mut()
+					.find(|a| *a == who)
+					.ok_or(Error::<T>::NotAnAuthority)?;
+				let old_key = slot.clone();
+				*slot = new_key.clone();
+				Ok(old_key)
 			})?;
-			debug::info!("Body: {}", body_str);
-			Self::deposit_event(RawEvent::NewDiaData(price, who));
+			Self::deposit_event(RawEvent::AuthorityRotated(old_key, new_key));
 			Ok(())
 		}
 
+		fn on_finalize(_block_number: T::BlockNumber) {
+			for (symbol, observations) in Observations::<T>::drain() {
+				let count = observations.len() as u32;
+				if let Some(aggregated) = Self::aggregate(&observations) {
+					let price = aggregated.price;
+					DiaData::<T>::insert(&symbol, aggregated);
+					Self::deposit_event(RawEvent::PriceCommitted(symbol, price, count));
+				}
+			}
+		}
+
 		fn offchain_worker(block_number: T::BlockNumber) {
 			let parent_hash = <system::Module<T>>::block_hash(block_number - 1.into());
 			debug::info!("Current block: {:?} (parent hash: {:?})", block_number, parent_hash);
 
-			let res = Self::fetch_data_and_submit_signed();
+			let res = if T::SUBMIT_UNSIGNED {
+				Self::fetch_data_and_submit_unsigned(block_number)
+			} else {
+				Self::fetch_data_and_submit_signed()
+			};
 			if let Err(e) = res {
 				debug::error!("Error: {}", e);
 			}
@@ -94,57 +367,212 @@ decl_module! {
 	}
 }
 
+impl<T: Trait> frame_support::unsigned::ValidateUnsigned for Module<T> {
+	type Call = Call<T>;
+
+	fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+		if let Call::submit_data_unsigned(payload, signature) = call {
+			if !Self::is_authority(&payload.public.clone().into_account()) {
+				return InvalidTransaction::BadSigner.into();
+			}
+			let signature_valid = SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone());
+			if !signature_valid {
+				return InvalidTransaction::BadProof.into();
+			}
+			Self::validate_transaction_parameters(&payload.quotation.symbol, &payload.block_number, &payload.public)
+		} else {
+			InvalidTransaction::Call.into()
+		}
+	}
+}
+
 impl<T: Trait> Module<T> {
+	/// Whether `who` is on the oracle authority allowlist.
+	fn is_authority(who: &T::AccountId) -> bool {
+		Authorities::<T>::get().contains(who)
+	}
+
+	/// Accept `origin` if it is root, or a signed origin belonging to a registered authority.
+	fn ensure_root_or_authority(origin: T::Origin) -> DispatchResult {
+		if ensure_root(origin.clone()).is_ok() {
+			return Ok(());
+		}
+		let who = ensure_signed(origin)?;
+		ensure!(Self::is_authority(&who), Error::<T>::NotAnAuthority);
+		Ok(())
+	}
+
+	/// `symbol` and `public` key the provides-tag so the pool tracks one outstanding
+	/// submission per (symbol, authority) pair rather than one per block: otherwise every
+	/// quotation submitted in a block, across all symbols and authorities, would collapse
+	/// onto the same tag and the pool would let only one of them through.
+	fn validate_transaction_parameters(
+		symbol: &[u8],
+		block_number: &T::BlockNumber,
+		public: &T::Public,
+	) -> TransactionValidity {
+		let next_unsigned_at = NextUnsignedAt::<T>::get(symbol);
+		if next_unsigned_at > *block_number {
+			return InvalidTransaction::Stale.into();
+		}
+
+		ValidTransaction::with_tag_prefix("DIAOCW")
+			.priority(T::UnsignedPriority::get())
+			.and_provides((symbol.to_vec(), public.clone()))
+			.longevity(5)
+			.propagate(true)
+			.build()
+	}
+
+	/// The latest committed price for `symbol`, or `None` if it has never been fed, so
+	/// other pallets can consume the oracle feed directly on-chain.
+	pub fn price_of(symbol: &[u8]) -> Option<u128> {
+		if DiaData::<T>::contains_key(symbol) {
+			Some(DiaData::<T>::get(symbol).price)
+		} else {
+			None
+		}
+	}
+
+	/// Record an observation from `who`, discarding it if it is an outlier against the
+	/// currently committed price. `on_finalize` aggregates surviving observations.
+	///
+	/// `who` may already have an observation for this symbol this block, e.g. a signed
+	/// worker querying several mirror endpoints submits once per endpoint: keep only the
+	/// latest one per account so a single operator's endpoint count can't outweigh other
+	/// authorities in the median.
+	fn record_observation(quotation: Quotation, who: T::AccountId) {
+		if Self::is_outlier(&quotation) {
+			debug::warn!("Discarding outlier observation for {:?}: {}", quotation.symbol, quotation.price);
+			return;
+		}
+		Observations::<T>::mutate(quotation.symbol.clone(), |observations| {
+			match observations.iter_mut().find(|(account, _)| account == &who) {
+				Some(existing) => existing.1 = quotation,
+				None => observations.push((who, quotation)),
+			}
+		});
+	}
+
+	/// Whether `quotation.price` deviates from the currently committed price for its
+	/// symbol by more than `T::OutlierTolerancePercent`. A symbol with no committed price
+	/// yet has no baseline to compare against, so nothing is rejected.
+	fn is_outlier(quotation: &Quotation) -> bool {
+		if !DiaData::<T>::contains_key(&quotation.symbol) {
+			return false;
+		}
+		let current = DiaData::<T>::get(&quotation.symbol).price;
+		let deviation = if quotation.price > current { quotation.price - current } else { current - quotation.price };
+		deviation.saturating_mul(100) > current.saturating_mul(T::OutlierTolerancePercent::get() as u128)
+	}
+
+	/// Combine a block's observations into a single `Quotation`: the median price and
+	/// volume across contributors, paired with the latest reported timestamp.
+	fn aggregate(observations: &[(T::AccountId, Quotation)]) -> Option<Quotation> {
+		if observations.is_empty() {
+			return None;
+		}
+		let symbol = observations[0].1.symbol.clone();
+		let price = Self::median(observations.iter().map(|(_, q)| q.price));
+		let volume_yesterday = Self::median(observations.iter().map(|(_, q)| q.volume_yesterday));
+		let timestamp = observations.iter().map(|(_, q)| q.timestamp).max().unwrap_or(0);
+		Some(Quotation { symbol, price, volume_yesterday, timestamp })
+	}
+
+	/// Median of an iterator of fixed-point values.
+	fn median(values: impl Iterator<Item = u128>) -> u128 {
+		let mut values: Vec<u128> = values.collect();
+		values.sort_unstable();
+		let mid = values.len() / 2;
+		if values.len() % 2 == 1 {
+			values[mid]
+		} else {
+			(values[mid - 1] + values[mid]) / 2
+		}
+	}
+
 	fn fetch_data_and_submit_signed() -> Result<(), &'static str> {
+		// Not pre-filtered to `Authorities`: `Signer` filters by local keystore public keys,
+		// and the allowlist is accounts, not public keys (see `Authorities`'s doc comment).
+		// `submit_data`'s `is_authority` check is what actually enforces the allowlist.
 		let signer = Signer::<T, T::AuthorityId>::all_accounts();
 		if !signer.can_sign() {
 			return Err(
-				"No local accounts available. Consider adding one via `author_insertKey` RPC."
+				"No local authority accounts available. Consider adding one via `author_insertKey` RPC and registering it with `add_authority`."
 			)?
 		}
-		let data = Self::fetch_data().map_err(|_| "Failed to fetch data")?;
-		// We have to borrow the data to capture it in the transaction
-		let ref_data = RefCell::new(data);
-
-		// Using `send_signed_transaction` associated type we create and submit a transaction
-		// representing the call, we've just created.
-		// Submit signed will return a vector of results for all accounts that were found in the
-		// local keystore with expected `KEY_TYPE`.
-		let results = signer.send_signed_transaction(
-			|_account| {
-				debug::info!("Submitting fetched data");
-				Call::submit_data(ref_data.borrow().into_mut().to_vec())
-			}
-		);
 
-		for (acc, res) in &results {
-			match res {
-				Ok(()) => debug::info!("[{:?}] Submitted fetched result", acc.id),
-				Err(e) => debug::error!("[{:?}] Failed to submit transaction: {:?}", acc.id, e),
+		for symbol in TrackedAssets::<T>::get() {
+			for quotation in Self::fetch_quotations(&symbol) {
+				// Using `send_signed_transaction` associated type we create and submit a
+				// transaction representing the call, we've just created.
+				// Submit signed will return a vector of results for all accounts that
+				// were found in the local keystore with expected `KEY_TYPE`.
+				let results = signer.send_signed_transaction(
+					|_account| {
+						debug::info!("Submitting observation for {:?}: {}", quotation.symbol, quotation.price);
+						Call::submit_data(quotation.clone())
+					}
+				);
+
+				for (acc, res) in &results {
+					match res {
+						Ok(()) => debug::info!("[{:?}] Submitted fetched result", acc.id),
+						Err(e) => debug::error!("[{:?}] Failed to submit transaction: {:?}", acc.id, e),
+					}
+				}
 			}
 		}
 
 		Ok(())
 	}
 
-	fn fetch_data() -> Result<Vec<u8>, http::Error> {
-		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(2_000));
-		let request = http::Request::get(
-			"https://api.diadata.org/v1/quotation/BTC"
-		);
-		let pending = request
-			.deadline(deadline)
-			.send()
-			.map_err(|_| http::Error::IoError)?;
+	/// Fetch data and submit it as an unsigned transaction carrying a signed payload, so
+	/// that a collator without any funded account can still feed the oracle.
+	fn fetch_data_and_submit_unsigned(block_number: T::BlockNumber) -> Result<(), &'static str> {
+		// Not pre-filtered to `Authorities` here either; `submit_data_unsigned`'s
+		// `is_authority` check (enforced via `ValidateUnsigned`) is the actual boundary.
+		let signer = Signer::<T, T::AuthorityId>::any_account();
 
-		let response = pending.try_wait(deadline)
-			.map_err(|_| http::Error::DeadlineReached)??;
-		if response.code != 200 {
-			debug::warn!("Unexpected status code: {}", response.code);
-			return Err(http::Error::Unknown);
+		for symbol in TrackedAssets::<T>::get() {
+			// Skip symbols whose current round isn't due yet, matching the block
+			// `ValidateUnsigned` will check a freshly stamped payload against.
+			if NextUnsignedAt::<T>::get(&symbol) > block_number {
+				continue;
+			}
+			for quotation in Self::fetch_quotations(&symbol) {
+				let (_, res) = signer.send_unsigned_transaction(
+					|account| PricePayload {
+						block_number,
+						quotation: quotation.clone(),
+						public: account.public.clone(),
+					},
+					|payload, signature| Call::submit_data_unsigned(payload, signature),
+				).ok_or("No local authority accounts available. Consider adding one via `author_insertKey` RPC and registering it with `add_authority`.")?;
+
+				res.map_err(|()| "Unable to submit unsigned transaction")?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Query every configured mirror endpoint for `symbol`, returning the quotations that
+	/// were fetched and parsed successfully. A misbehaving or unreachable mirror simply
+	/// contributes no observation rather than failing the whole round.
+	fn fetch_quotations(symbol: &[u8]) -> Vec<Quotation> {
+		let mut quotations = Vec::new();
+		for endpoint in T::ENDPOINTS {
+			match Self::fetch_one(*endpoint, symbol) {
+				Ok(quotation) => quotations.push(quotation),
+				Err(e) => debug::error!("Failed to fetch {:?} from {}: {:?}", symbol, endpoint, e),
+			}
 		}
+		quotations
+	}
 
-		let body = response.body().collect::<Vec<u8>>();
+	fn fetch_one(endpoint: &'static str, symbol: &[u8]) -> Result<Quotation, http::Error> {
+		let body = T::DataSource::fetch(endpoint, symbol)?;
 
 		// Read body as string and print log, otherwise we only use bytes
 		let body_str = sp_runtime::sp_std::str::from_utf8(&body).map_err(|_| {
@@ -153,6 +581,84 @@ impl<T: Trait> Module<T> {
 		})?;
 		debug::warn!("Got response: {}", &body_str);
 
-		Ok(body)
+		Self::parse_quotation(symbol, body_str).ok_or(http::Error::Unknown)
+	}
+
+	/// Scan a DIA quotation JSON body for the `"Price"`, `"VolumeYesterdayUSD"` and
+	/// `"Time"` fields, parsing the numbers that follow them.
+	fn parse_quotation(symbol: &[u8], body: &str) -> Option<Quotation> {
+		let price = Self::to_fixed_point(Self::extract_field(body, "\"Price\":")?)?;
+		let volume_yesterday = Self::extract_field(body, "\"VolumeYesterdayUSD\":")
+			.and_then(Self::to_fixed_point)
+			.unwrap_or(0);
+		let timestamp = Self::extract_field(body, "\"Time\":")
+			.and_then(Self::parse_rfc3339_timestamp)
+			.unwrap_or(0);
+		Some(Quotation { symbol: symbol.to_vec(), price, volume_yesterday, timestamp })
+	}
+
+	/// Find `key` in `body` and return the raw value that follows it, up to the next
+	/// field separator. Strips a surrounding pair of quotes if the value is a string.
+	fn extract_field<'a>(body: &'a str, key: &str) -> Option<&'a str> {
+		let start = body.find(key)? + key.len();
+		let rest = body[start..].trim_start().trim_start_matches('"');
+		let end = rest.find(|c: char| c == ',' || c == '}' || c == '"').unwrap_or(rest.len());
+		Some(rest[..end].trim())
+	}
+
+	/// Convert a decimal number string such as `"1234.5678"` into a fixed-point `u128`
+	/// scaled by 10^`PRICE_DECIMALS`, without ever materialising a float.
+	fn to_fixed_point(number: &str) -> Option<u128> {
+		let (integer_part, fractional_part) = match number.find('.') {
+			Some(idx) => (&number[..idx], &number[idx + 1..]),
+			None => (number, ""),
+		};
+		let integer_value: u128 = integer_part.parse().ok()?;
+		let mut fractional_value: u128 = 0;
+		let mut digits = 0u32;
+		for c in fractional_part.chars().take(PRICE_DECIMALS as usize) {
+			fractional_value = fractional_value * 10 + c.to_digit(10)? as u128;
+			digits += 1;
+		}
+		for _ in digits..PRICE_DECIMALS {
+			fractional_value *= 10;
+		}
+		let scale = 10u128.pow(PRICE_DECIMALS);
+		Some(integer_value.saturating_mul(scale).saturating_add(fractional_value))
+	}
+
+	/// Parse a DIA `Time` field such as `"2021-05-10T14:30:00Z"` into a Unix timestamp in
+	/// seconds. DIA always reports UTC, so the trailing offset/`Z` is ignored rather than
+	/// interpreted.
+	fn parse_rfc3339_timestamp(value: &str) -> Option<u64> {
+		let bytes = value.as_bytes();
+		if bytes.len() < 19 {
+			return None;
+		}
+		let year: i64 = value.get(0..4)?.parse().ok()?;
+		let month: u32 = value.get(5..7)?.parse().ok()?;
+		let day: u32 = value.get(8..10)?.parse().ok()?;
+		let hour: u64 = value.get(11..13)?.parse().ok()?;
+		let minute: u64 = value.get(14..16)?.parse().ok()?;
+		let second: u64 = value.get(17..19)?.parse().ok()?;
+
+		let days = Self::days_from_civil(year, month, day)?;
+		Some((days as u64) * 86_400 + hour * 3_600 + minute * 60 + second)
+	}
+
+	/// Days since the Unix epoch (1970-01-01) for a given Gregorian calendar date, using
+	/// Howard Hinnant's `days_from_civil` algorithm so the conversion stays pure integer
+	/// arithmetic, with no floats or libc date functions, for `no_std`.
+	fn days_from_civil(year: i64, month: u32, day: u32) -> Option<i64> {
+		if month == 0 || month > 12 || day == 0 || day > 31 {
+			return None;
+		}
+		let y = if month <= 2 { year - 1 } else { year };
+		let era = if y >= 0 { y } else { y - 399 } / 400;
+		let year_of_era = (y - era * 400) as i64;
+		let month_index = ((month as i64 + 9) % 12) as i64;
+		let day_of_year = (153 * month_index + 2) / 5 + day as i64 - 1;
+		let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+		Some(era * 146_097 + day_of_era - 719_468)
 	}
 }
@@ -0,0 +1,251 @@
+use super::*;
+
+use frame_support::{impl_outer_event, impl_outer_origin, parameter_types, weights::Weight};
+use sp_core::H256;
+use sp_runtime::{
+	testing::{Header, TestXt},
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup},
+	MultiSignature, MultiSigner, Perbill,
+};
+
+impl_outer_origin! {
+	pub enum Origin for Test where system = frame_system {}
+}
+
+mod dia_ocw {
+	pub use crate::Event;
+}
+
+impl_outer_event! {
+	pub enum TestEvent for Test {
+		frame_system<T>,
+		dia_ocw<T>,
+	}
+}
+
+type AccountId = <MultiSigner as IdentifyAccount>::AccountId;
+type Extrinsic = TestXt<Call<Test>, ()>;
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const UnsignedPriority: TransactionPriority = 100;
+	pub const OutlierTolerancePercent: u32 = 10;
+}
+
+impl frame_system::Trait for Test {
+	type Origin = Origin;
+	type Call = Call<Test>;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = TestEvent;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type MaximumBlockLength = MaximumBlockLength;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type Version = ();
+	type ModuleToIndex = ();
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	Call<Test>: From<LocalCall>,
+{
+	type OverarchingCall = Call<Test>;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> CreateSignedTransaction<LocalCall> for Test
+where
+	Call<Test>: From<LocalCall>,
+{
+	fn create_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+		call: Call<Test>,
+		_public: MultiSigner,
+		_account: AccountId,
+		nonce: u64,
+	) -> Option<(Call<Test>, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+impl SigningTypes for Test {
+	type Public = MultiSigner;
+	type Signature = MultiSignature;
+}
+
+/// Always returns a canned quotation body, standing in for a mirror that is up.
+pub struct Succeeds;
+impl DataSource for Succeeds {
+	fn fetch(_endpoint: &'static str, _symbol: &[u8]) -> Result<Vec<u8>, http::Error> {
+		Ok(br#"{"Price": 123.45}"#.to_vec())
+	}
+}
+
+/// Always errors, standing in for a mirror that is down.
+pub struct Fails;
+impl DataSource for Fails {
+	fn fetch(_endpoint: &'static str, _symbol: &[u8]) -> Result<Vec<u8>, http::Error> {
+		Err(http::Error::Unknown)
+	}
+}
+
+impl Trait for Test {
+	type AuthorityId = crypto::TestAuthId;
+	type Event = TestEvent;
+	type Call = Call<Test>;
+	const SUBMIT_UNSIGNED: bool = false;
+	type UnsignedPriority = UnsignedPriority;
+	const ENDPOINTS: &'static [&'static str] = &["https://api.diadata.org/v1/quotation/"];
+	type OutlierTolerancePercent = OutlierTolerancePercent;
+	type DataSource = FallbackSource<Fails, Succeeds>;
+}
+
+type DiaOcw = Module<Test>;
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+}
+
+fn quotation(symbol: &[u8], price: u128) -> Quotation {
+	Quotation { symbol: symbol.to_vec(), price, volume_yesterday: 0, timestamp: 0 }
+}
+
+#[test]
+fn to_fixed_point_scales_decimals() {
+	assert_eq!(DiaOcw::to_fixed_point("123.45"), Some(123_45_000_000));
+	assert_eq!(DiaOcw::to_fixed_point("1"), Some(1_00_000_000));
+	assert_eq!(DiaOcw::to_fixed_point("0.000000001"), Some(0));
+	assert_eq!(DiaOcw::to_fixed_point("abc"), None);
+}
+
+#[test]
+fn extract_field_reads_numbers_and_quoted_strings() {
+	let body = r#"{"Symbol":"BTC","Price": 54321.1234,"VolumeYesterdayUSD":1000,"Time":"2021-05-10T14:30:00Z"}"#;
+	assert_eq!(DiaOcw::extract_field(body, "\"Price\":"), Some("54321.1234"));
+	assert_eq!(DiaOcw::extract_field(body, "\"Time\":"), Some("2021-05-10T14:30:00Z"));
+	assert_eq!(DiaOcw::extract_field(body, "\"Missing\":"), None);
+}
+
+#[test]
+fn parse_quotation_builds_fixed_point_quotation_with_timestamp() {
+	let body = r#"{"Symbol":"BTC","Price": 54321.12,"VolumeYesterdayUSD": 42,"Time":"2021-05-10T14:30:00Z"}"#;
+	let parsed = DiaOcw::parse_quotation(b"BTC", body).unwrap();
+	assert_eq!(parsed.symbol, b"BTC".to_vec());
+	assert_eq!(parsed.price, 54321_12_000_000);
+	assert_eq!(parsed.volume_yesterday, 42_00_000_000);
+	assert_eq!(parsed.timestamp, 1_620_657_000);
+}
+
+#[test]
+fn parse_quotation_requires_a_price() {
+	let body = r#"{"Symbol":"BTC","VolumeYesterdayUSD": 42,"Time":"2021-05-10T14:30:00Z"}"#;
+	assert!(DiaOcw::parse_quotation(b"BTC", body).is_none());
+}
+
+#[test]
+fn median_handles_odd_and_even_counts() {
+	assert_eq!(DiaOcw::median(vec![3, 1, 2].into_iter()), 2);
+	assert_eq!(DiaOcw::median(vec![4, 1, 2, 3].into_iter()), 2);
+}
+
+#[test]
+fn is_outlier_allows_the_first_observation_and_small_deviations() {
+	new_test_ext().execute_with(|| {
+		let baseline = quotation(b"BTC", 100_00_000_000);
+		assert!(!DiaOcw::is_outlier(&baseline));
+
+		DiaData::<Test>::insert(b"BTC".to_vec(), baseline);
+		assert!(!DiaOcw::is_outlier(&quotation(b"BTC", 105_00_000_000)));
+		assert!(DiaOcw::is_outlier(&quotation(b"BTC", 200_00_000_000)));
+	});
+}
+
+#[test]
+fn record_observation_keeps_only_the_latest_per_account() {
+	new_test_ext().execute_with(|| {
+		let who = AccountId::from([9u8; 32]);
+		DiaOcw::record_observation(quotation(b"BTC", 100), who.clone());
+		DiaOcw::record_observation(quotation(b"BTC", 110), who.clone());
+
+		let observations = Observations::<Test>::get(b"BTC".to_vec());
+		assert_eq!(observations.len(), 1);
+		assert_eq!(observations[0].1.price, 110);
+	});
+}
+
+#[test]
+fn record_observation_discards_outliers() {
+	new_test_ext().execute_with(|| {
+		DiaData::<Test>::insert(b"BTC".to_vec(), quotation(b"BTC", 100_00_000_000));
+		DiaOcw::record_observation(quotation(b"BTC", 200_00_000_000), AccountId::from([1u8; 32]));
+
+		assert!(Observations::<Test>::get(b"BTC".to_vec()).is_empty());
+	});
+}
+
+#[test]
+fn aggregate_commits_median_price_and_latest_timestamp() {
+	let observations = vec![
+		(AccountId::from([1u8; 32]), Quotation { symbol: b"BTC".to_vec(), price: 100, volume_yesterday: 10, timestamp: 5 }),
+		(AccountId::from([2u8; 32]), Quotation { symbol: b"BTC".to_vec(), price: 300, volume_yesterday: 30, timestamp: 7 }),
+		(AccountId::from([3u8; 32]), Quotation { symbol: b"BTC".to_vec(), price: 200, volume_yesterday: 20, timestamp: 6 }),
+	];
+
+	let aggregated = DiaOcw::aggregate(&observations).unwrap();
+	assert_eq!(aggregated.price, 200);
+	assert_eq!(aggregated.volume_yesterday, 20);
+	assert_eq!(aggregated.timestamp, 7);
+}
+
+#[test]
+fn aggregate_returns_none_for_no_observations() {
+	assert!(DiaOcw::aggregate(&[]).is_none());
+}
+
+#[test]
+fn validate_transaction_parameters_rate_limits_per_symbol_not_globally() {
+	new_test_ext().execute_with(|| {
+		let public = MultiSigner::from(sp_core::sr25519::Public::from_raw([0u8; 32]));
+		NextUnsignedAt::<Test>::insert(b"BTC".to_vec(), 5u64);
+
+		assert!(DiaOcw::validate_transaction_parameters(b"BTC", &4u64, &public).is_err());
+		assert!(DiaOcw::validate_transaction_parameters(b"BTC", &5u64, &public).is_ok());
+		// ETH has never been rate-limited, so BTC's NextUnsignedAt doesn't block it.
+		assert!(DiaOcw::validate_transaction_parameters(b"ETH", &4u64, &public).is_ok());
+	});
+}
+
+#[test]
+fn fallback_source_falls_back_when_the_primary_errors() {
+	let body = FallbackSource::<Fails, Succeeds>::fetch("https://mirror/", b"BTC").unwrap();
+	assert_eq!(body, br#"{"Price": 123.45}"#.to_vec());
+}
+
+#[test]
+fn fallback_source_prefers_the_primary_when_it_succeeds() {
+	let body = FallbackSource::<Succeeds, Fails>::fetch("https://mirror/", b"BTC").unwrap();
+	assert_eq!(body, br#"{"Price": 123.45}"#.to_vec());
+}
+
+#[test]
+fn fallback_source_propagates_error_when_both_fail() {
+	assert!(FallbackSource::<Fails, Fails>::fetch("https://mirror/", b"BTC").is_err());
+}